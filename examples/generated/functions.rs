@@ -1,4 +1,4 @@
-fn clamp(v: f64, lo: f64, hi: f64) -> f64 {
+fn clamp<T: PartialOrd>(v: T, lo: T, hi: T) -> T {
     if (v < lo) {
         return lo;
     } else {
@@ -10,7 +10,61 @@ fn clamp(v: f64, lo: f64, hi: f64) -> f64 {
     }
 }
 
+fn clamp_f64(v: f64, lo: f64, hi: f64) -> f64 {
+    debug_assert!(lo <= hi);
+    if v.is_nan() {
+        return v;
+    }
+    if (v < lo) {
+        return lo;
+    } else {
+        if (v > hi) {
+            return hi;
+        } else {
+            return v;
+        }
+    }
+}
+
+fn clamp_slice(data: &mut [f64], lo: f64, hi: f64) {
+    debug_assert!(lo <= hi);
+    for v in data.iter_mut() {
+        if v.is_nan() {
+            continue;
+        }
+        if (*v < lo) {
+            *v = lo;
+        } else if (*v > hi) {
+            *v = hi;
+        }
+    }
+}
+
+fn clamp_slice_generic<T: PartialOrd + Copy>(data: &mut [T], lo: T, hi: T) {
+    for v in data.iter_mut() {
+        *v = clamp(*v, lo, hi);
+    }
+}
+
+fn clamp_range(v: f64, range: std::ops::RangeInclusive<f64>) -> f64 {
+    clamp_f64(v, *range.start(), *range.end())
+}
+
 fn main() {
     let mut result: f64 = clamp(10.0, 0.0, 5.0);
     println!("{:?}", result);
+
+    let result: f64 = clamp_f64(f64::NAN, 0.0, 5.0);
+    println!("{:?}", result);
+
+    let mut samples: [f64; 3] = [-1.0, 2.0, 10.0];
+    clamp_slice(&mut samples, 0.0, 5.0);
+    println!("{:?}", samples);
+
+    let mut ordinals: [i32; 3] = [-1, 2, 10];
+    clamp_slice_generic(&mut ordinals, 0, 5);
+    println!("{:?}", ordinals);
+
+    let result: f64 = clamp_range(10.0, 0.0..=5.0);
+    println!("{:?}", result);
 }